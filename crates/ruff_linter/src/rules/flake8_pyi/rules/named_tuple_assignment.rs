@@ -1,10 +1,13 @@
-use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_diagnostics::{Diagnostic, Edit, Fix, FixAvailability, Violation};
 use ruff_macros::{derive_message_formats, violation};
-use ruff_python_ast::ExprCall;
+use ruff_python_ast::{self as ast, Expr, ExprCall, Keyword, PythonVersion, Stmt};
 use ruff_python_semantic::Modules;
+use ruff_python_stdlib::identifiers::is_identifier;
+use ruff_python_stdlib::keyword::is_keyword;
 use ruff_text_size::Ranged;
 
 use crate::checkers::ast::Checker;
+use crate::importer::ImportRequest;
 
 /// ## What it does
 /// Checks for uses of `collections.namedtuple` in stub files.
@@ -39,6 +42,8 @@ use crate::checkers::ast::Checker;
 pub struct NamedTupleAssignment;
 
 impl Violation for NamedTupleAssignment {
+    const FIX_AVAILABILITY: FixAvailability = FixAvailability::Sometimes;
+
     #[derive_message_formats]
     fn message(&self) -> String {
         format!("Use class-based syntax for NamedTuples")
@@ -51,26 +56,723 @@ impl Violation for NamedTupleAssignment {
 
 /// PYI028
 pub(crate) fn named_tuple_assignment(checker: &mut Checker, expr: &ExprCall) {
+    if !checker.semantic().seen_module(
+        Modules::TYPING | Modules::TYPING_EXTENSIONS | Modules::COLLECTIONS,
+    ) {
+        return;
+    }
+
+    let func = expr.func.as_ref();
     if !checker
         .semantic()
-        .seen_module(Modules::TYPING | Modules::TYPING_EXTENSIONS)
+        .resolve_qualified_name(func)
+        .is_some_and(|qualified_name| {
+            matches!(
+                qualified_name.segments(),
+                ["typing" | "typing_extensions", "NamedTuple"]
+                    | ["NamedTuple"]
+                    | ["collections", "namedtuple"]
+                    | ["namedtuple"]
+            )
+        })
     {
         return;
     }
 
-    let func = expr.func.as_ref();
-    if checker
+    // A call used directly as a class base, e.g. `class Point(NamedTuple("Point", ...)):`,
+    // is handled by `named_tuple_call_base` instead. Bail out entirely here rather than
+    // just skipping the fix, otherwise the same call produces two diagnostics.
+    if matches!(checker.semantic().current_statement(), Stmt::ClassDef(_)) {
+        return;
+    }
+
+    let mut diagnostic = Diagnostic::new(NamedTupleAssignment, func.range());
+
+    if let Some(fix) = build_fix(checker, expr) {
+        diagnostic.set_fix(fix);
+    }
+
+    checker.diagnostics.push(diagnostic);
+}
+
+/// PYI028
+///
+/// Detects the functional constructor used directly as a class base, e.g.
+/// `class Point(NamedTuple("Point", [("x", int), ("y", int)])): ...`.
+pub(crate) fn named_tuple_call_base(checker: &mut Checker, class_def: &ast::StmtClassDef) {
+    for base in class_def.bases() {
+        let Expr::Call(call) = base else {
+            continue;
+        };
+        let is_named_tuple_factory =
+            checker
+                .semantic()
+                .resolve_qualified_name(&call.func)
+                .is_some_and(|qualified_name| {
+                    matches!(
+                        qualified_name.segments(),
+                        ["typing" | "typing_extensions", "NamedTuple"]
+                            | ["NamedTuple"]
+                            | ["collections", "namedtuple"]
+                            | ["namedtuple"]
+                    )
+                });
+        if !is_named_tuple_factory {
+            continue;
+        }
+
+        let mut diagnostic = Diagnostic::new(NamedTupleAssignment, call.func.range());
+
+        if let Some(fix) = build_class_base_fix(checker, class_def, call) {
+            diagnostic.set_fix(fix);
+        }
+
+        checker.diagnostics.push(diagnostic);
+    }
+}
+
+/// Returns `true` if `class_def`'s body already declares a member named `name`,
+/// as a field, plain assignment, or method.
+fn class_defines_member(class_def: &ast::StmtClassDef, name: &str) -> bool {
+    class_def.body.iter().any(|stmt| match stmt {
+        Stmt::AnnAssign(ast::StmtAnnAssign { target, .. }) => {
+            matches!(target.as_ref(), Expr::Name(existing) if existing.id.as_str() == name)
+        }
+        Stmt::Assign(ast::StmtAssign { targets, .. }) => {
+            matches!(targets.as_slice(), [Expr::Name(existing)] if existing.id.as_str() == name)
+        }
+        Stmt::FunctionDef(ast::StmtFunctionDef { name: fn_name, .. }) => fn_name.as_str() == name,
+        _ => false,
+    })
+}
+
+/// Render `count` field lines for insertion right before a class body's existing
+/// first statement. The source already carries that statement's indentation
+/// immediately before the insertion point, so the first field must *not* get a
+/// leading `indent` (that would double it up) — but a trailing `line_ending` +
+/// `indent` is needed afterwards, to restore the indentation for the statement
+/// that now follows our inserted text. Returns `None` if there's nothing to
+/// insert.
+fn render_inserted_fields(
+    count: usize,
+    indent: &str,
+    line_ending: &str,
+    mut render: impl FnMut(usize, &mut String),
+) -> Option<String> {
+    if count == 0 {
+        return None;
+    }
+    let mut text = String::new();
+    for i in 0..count {
+        if i > 0 {
+            text.push_str(line_ending);
+            text.push_str(indent);
+        }
+        render(i, &mut text);
+    }
+    text.push_str(line_ending);
+    text.push_str(indent);
+    Some(text)
+}
+
+/// Render `count` field lines to replace a body that is just the `...` token.
+/// Unlike [`render_inserted_fields`], no leading or trailing indent is added,
+/// since the whole token (including its own indentation context) is replaced
+/// in place and nothing follows it.
+fn render_replaced_fields(
+    count: usize,
+    indent: &str,
+    line_ending: &str,
+    mut render: impl FnMut(usize, &mut String),
+) -> String {
+    if count == 0 {
+        return "...".to_string();
+    }
+    let mut text = String::new();
+    for i in 0..count {
+        if i > 0 {
+            text.push_str(line_ending);
+            text.push_str(indent);
+        }
+        render(i, &mut text);
+    }
+    text
+}
+
+/// Fold the fields of a functional constructor used as a class base into the
+/// subclass body, keeping any methods the user already defined.
+fn build_class_base_fix(
+    checker: &Checker,
+    class_def: &ast::StmtClassDef,
+    call: &ExprCall,
+) -> Option<Fix> {
+    let is_collections_namedtuple = checker
         .semantic()
-        .resolve_qualified_name(func)
+        .resolve_qualified_name(&call.func)
         .is_some_and(|qualified_name| {
             matches!(
                 qualified_name.segments(),
-                ["typing" | "typing_extensions", "NamedTuple"] | ["NamedTuple"]
+                ["collections", "namedtuple"] | ["namedtuple"]
+            )
+        });
+    if is_collections_namedtuple {
+        return build_class_base_collections_fix(checker, class_def, call);
+    }
+
+    let [_typename, rest @ ..] = call.arguments.args.as_ref() else {
+        return None;
+    };
+    let members = match (rest, call.arguments.keywords.as_ref()) {
+        ([fields], []) => collect_list_members(fields)?,
+        ([], keywords) if !keywords.is_empty() => collect_keyword_members(keywords)?,
+        ([], []) => Vec::new(),
+        _ => return None,
+    };
+
+    // Suppress the fix if the class already declares one of the fields itself,
+    // to avoid introducing a duplicate-name error.
+    if members
+        .iter()
+        .any(|(name, _)| class_defines_member(class_def, name))
+    {
+        return None;
+    }
+
+    let indent = checker.stylist().indentation();
+    let line_ending = checker.stylist().line_ending().as_str();
+    let render_field = |i: usize, text: &mut String| {
+        let (name, type_expr) = &members[i];
+        text.push_str(name);
+        text.push_str(": ");
+        text.push_str(checker.locator().slice(type_expr.range()));
+    };
+
+    // Replace the functional call with a bare reference to the factory it resolved to
+    // (e.g. `NamedTuple("Point", ...)` -> `NamedTuple`).
+    let base_edit = Edit::range_replacement(
+        checker.locator().slice(call.func.range()).to_string(),
+        call.range(),
+    );
+
+    let [first, ..] = class_def.body.as_slice() else {
+        return None;
+    };
+    let is_ellipsis_only = matches!(
+        class_def.body.as_slice(),
+        [Stmt::Expr(ast::StmtExpr { value, .. })] if matches!(value.as_ref(), Expr::EllipsisLiteral(_))
+    );
+
+    let body_edit = if is_ellipsis_only {
+        (!members.is_empty()).then(|| {
+            Edit::range_replacement(
+                render_replaced_fields(members.len(), indent, line_ending, render_field),
+                first.range(),
             )
         })
+    } else {
+        render_inserted_fields(members.len(), indent, line_ending, render_field)
+            .map(|text| Edit::insertion(text, first.start()))
+    };
+
+    Some(match body_edit {
+        Some(body_edit) => Fix::unsafe_edits(base_edit, [body_edit]),
+        None => Fix::unsafe_edit(base_edit),
+    })
+}
+
+/// Fold the fields of a `collections.namedtuple`/bare `namedtuple` call used as a
+/// class base into the subclass body, mirroring `build_collections_namedtuple_fix`'s
+/// handling of `rename`, `defaults`, and `Incomplete`-typed fields, but inserted
+/// into an existing class body rather than replacing a whole assignment.
+fn build_class_base_collections_fix(
+    checker: &Checker,
+    class_def: &ast::StmtClassDef,
+    call: &ExprCall,
+) -> Option<Fix> {
+    let [_typename, rest @ ..] = call.arguments.args.as_ref() else {
+        return None;
+    };
+    let [field_names_expr] = rest else {
+        return None;
+    };
+    let names = collect_namedtuple_field_names(field_names_expr)?;
+
+    let rename = match find_keyword_value(call, "rename") {
+        None => false,
+        Some(Expr::BooleanLiteral(ast::ExprBooleanLiteral { value, .. })) => *value,
+        Some(_) => return None,
+    };
+
+    let defaults = match find_keyword_value(call, "defaults") {
+        None => &[][..],
+        Some(Expr::List(ast::ExprList { elts, .. }) | Expr::Tuple(ast::ExprTuple { elts, .. })) => {
+            elts.as_slice()
+        }
+        Some(_) => return None,
+    };
+    if defaults.len() > names.len() {
+        return None;
+    }
+
+    let mut seen = std::collections::HashSet::new();
+    let mut field_names = Vec::with_capacity(names.len());
+    for (i, name) in names.iter().enumerate() {
+        let is_valid =
+            is_identifier(name) && !is_keyword(name) && !name.starts_with('_') && seen.insert(*name);
+        if is_valid {
+            field_names.push((*name).to_string());
+        } else if rename {
+            field_names.push(format!("_{i}"));
+        } else {
+            return None;
+        }
+    }
+
+    // Suppress the fix if the class already declares one of the fields itself,
+    // to avoid introducing a duplicate-name error.
+    if field_names
+        .iter()
+        .any(|name| class_defines_member(class_def, name))
     {
-        checker
-            .diagnostics
-            .push(Diagnostic::new(NamedTupleAssignment, func.range()));
+        return None;
+    }
+
+    let (incomplete_edit, incomplete_binding) = checker
+        .importer()
+        .get_or_import_symbol(
+            &ImportRequest::import("_typeshed", "Incomplete"),
+            call.start(),
+            checker.semantic(),
+        )
+        .ok()?;
+    let (named_tuple_edit, named_tuple_binding) = checker
+        .importer()
+        .get_or_import_symbol(
+            &ImportRequest::import("typing", "NamedTuple"),
+            call.start(),
+            checker.semantic(),
+        )
+        .ok()?;
+
+    let indent = checker.stylist().indentation();
+    let line_ending = checker.stylist().line_ending().as_str();
+    let default_offset = field_names.len() - defaults.len();
+    let render_field = |i: usize, text: &mut String| {
+        text.push_str(&field_names[i]);
+        text.push_str(": ");
+        text.push_str(&incomplete_binding);
+        if i >= default_offset {
+            text.push_str(" = ");
+            text.push_str(checker.locator().slice(defaults[i - default_offset].range()));
+        }
+    };
+
+    // Replace the functional call with the (possibly newly imported) `NamedTuple` base.
+    let base_edit = Edit::range_replacement(named_tuple_binding, call.range());
+
+    let [first, ..] = class_def.body.as_slice() else {
+        return None;
+    };
+    let is_ellipsis_only = matches!(
+        class_def.body.as_slice(),
+        [Stmt::Expr(ast::StmtExpr { value, .. })] if matches!(value.as_ref(), Expr::EllipsisLiteral(_))
+    );
+
+    let body_edit = if is_ellipsis_only {
+        (!field_names.is_empty()).then(|| {
+            Edit::range_replacement(
+                render_replaced_fields(field_names.len(), indent, line_ending, render_field),
+                first.range(),
+            )
+        })
+    } else {
+        render_inserted_fields(field_names.len(), indent, line_ending, render_field)
+            .map(|text| Edit::insertion(text, first.start()))
+    };
+
+    Some(match body_edit {
+        Some(body_edit) => {
+            Fix::unsafe_edits(incomplete_edit, [named_tuple_edit, base_edit, body_edit])
+        }
+        None => Fix::unsafe_edits(incomplete_edit, [named_tuple_edit, base_edit]),
+    })
+}
+
+/// Attempt to build a fix that rewrites a functional `NamedTuple` assignment
+/// into the equivalent class-based syntax.
+fn build_fix(checker: &Checker, call: &ExprCall) -> Option<Fix> {
+    // The call must be the value of a simple assignment: `Person = NamedTuple(...)`.
+    let Stmt::Assign(assign) = checker.semantic().current_statement() else {
+        return None;
+    };
+    if assign.value.as_ref().range() != call.range() {
+        return None;
+    }
+    let [Expr::Name(target)] = assign.targets.as_slice() else {
+        return None;
+    };
+
+    // The first positional argument must be a string literal matching the target name.
+    let [typename, rest @ ..] = call.arguments.args.as_ref() else {
+        return None;
+    };
+    let Expr::StringLiteral(typename) = typename else {
+        return None;
+    };
+    if typename.value.to_str() != target.id.as_str() {
+        return None;
+    }
+
+    let is_collections_namedtuple = checker
+        .semantic()
+        .resolve_qualified_name(&call.func)
+        .is_some_and(|qualified_name| {
+            matches!(
+                qualified_name.segments(),
+                ["collections", "namedtuple"] | ["namedtuple"]
+            )
+        });
+    if is_collections_namedtuple {
+        return build_collections_namedtuple_fix(checker, call, assign, target, rest);
+    }
+
+    // The list-of-pairs form (`NamedTuple("Person", [("name", str)])`) and the
+    // keyword form (`NamedTuple("Person", name=str)`) are mutually exclusive.
+    let members = match (rest, call.arguments.keywords.as_ref()) {
+        ([fields], []) => collect_list_members(fields)?,
+        ([], keywords) if !keywords.is_empty() => collect_keyword_members(keywords)?,
+        ([], []) => Vec::new(),
+        _ => return None,
+    };
+
+    let indent = checker.stylist().indentation();
+    let line_ending = checker.stylist().line_ending().as_str();
+
+    let mut body = String::new();
+    if members.is_empty() {
+        body.push_str(indent);
+        body.push_str("...");
+    } else {
+        for (i, (name, type_expr)) in members.iter().enumerate() {
+            if i > 0 {
+                body.push_str(line_ending);
+            }
+            body.push_str(indent);
+            body.push_str(name);
+            body.push_str(": ");
+            // Preserve the annotation's source verbatim, rather than re-rendering it,
+            // so that complex expressions (e.g. `int | None`) survive untouched.
+            body.push_str(checker.locator().slice(type_expr.range()));
+        }
+    }
+
+    let type_vars = collect_type_vars(checker, members.iter().map(|(_, type_expr)| *type_expr))?;
+
+    let (header, import_edit) = if type_vars.is_empty() {
+        (format!("class {name}(NamedTuple):", name = target.id), None)
+    } else if checker.settings.target_version >= PythonVersion::Py312 {
+        (
+            format!(
+                "class {name}[{params}](NamedTuple):",
+                name = target.id,
+                params = type_vars.join(", "),
+            ),
+            None,
+        )
+    } else {
+        let (import_edit, binding) = checker
+            .importer()
+            .get_or_import_symbol(
+                &ImportRequest::import("typing", "Generic"),
+                call.start(),
+                checker.semantic(),
+            )
+            .ok()?;
+        (
+            format!(
+                "class {name}(NamedTuple, {binding}[{params}]):",
+                name = target.id,
+                params = type_vars.join(", "),
+            ),
+            Some(import_edit),
+        )
+    };
+
+    let content = format!("{header}{line_ending}{body}");
+
+    let fix_edit = Edit::range_replacement(content, assign.range());
+    Some(match import_edit {
+        Some(import_edit) => Fix::unsafe_edits(import_edit, [fix_edit]),
+        None => Fix::unsafe_edit(fix_edit),
+    })
+}
+
+/// Attempt to build a fix that migrates a `collections.namedtuple` assignment to
+/// `typing.NamedTuple` class syntax. Untyped fields are annotated with the stub
+/// convention `_typeshed.Incomplete`; `defaults` are wired up as trailing
+/// `= ...`-style assignments, and `rename` replaces invalid field names with
+/// their positional `_N` placeholder, matching `collections.namedtuple` itself.
+fn build_collections_namedtuple_fix(
+    checker: &Checker,
+    call: &ExprCall,
+    assign: &ast::StmtAssign,
+    target: &ast::ExprName,
+    rest: &[Expr],
+) -> Option<Fix> {
+    let [field_names_expr] = rest else {
+        return None;
+    };
+    let names = collect_namedtuple_field_names(field_names_expr)?;
+
+    let rename = match find_keyword_value(call, "rename") {
+        None => false,
+        Some(Expr::BooleanLiteral(ast::ExprBooleanLiteral { value, .. })) => *value,
+        Some(_) => return None,
+    };
+
+    let defaults = match find_keyword_value(call, "defaults") {
+        None => &[][..],
+        Some(Expr::List(ast::ExprList { elts, .. }) | Expr::Tuple(ast::ExprTuple { elts, .. })) => {
+            elts.as_slice()
+        }
+        Some(_) => return None,
+    };
+    if defaults.len() > names.len() {
+        return None;
+    }
+
+    let mut seen = std::collections::HashSet::new();
+    let mut field_names = Vec::with_capacity(names.len());
+    for (i, name) in names.iter().enumerate() {
+        let is_valid =
+            is_identifier(name) && !is_keyword(name) && !name.starts_with('_') && seen.insert(*name);
+        if is_valid {
+            field_names.push((*name).to_string());
+        } else if rename {
+            field_names.push(format!("_{i}"));
+        } else {
+            return None;
+        }
+    }
+
+    let (incomplete_edit, incomplete_binding) = checker
+        .importer()
+        .get_or_import_symbol(
+            &ImportRequest::import("_typeshed", "Incomplete"),
+            call.start(),
+            checker.semantic(),
+        )
+        .ok()?;
+    let (named_tuple_edit, named_tuple_binding) = checker
+        .importer()
+        .get_or_import_symbol(
+            &ImportRequest::import("typing", "NamedTuple"),
+            call.start(),
+            checker.semantic(),
+        )
+        .ok()?;
+
+    let indent = checker.stylist().indentation();
+    let line_ending = checker.stylist().line_ending().as_str();
+    let default_offset = field_names.len() - defaults.len();
+
+    let mut body = String::new();
+    if field_names.is_empty() {
+        body.push_str(indent);
+        body.push_str("...");
+    } else {
+        for (i, name) in field_names.iter().enumerate() {
+            if i > 0 {
+                body.push_str(line_ending);
+            }
+            body.push_str(indent);
+            body.push_str(name);
+            body.push_str(": ");
+            body.push_str(&incomplete_binding);
+            if i >= default_offset {
+                body.push_str(" = ");
+                body.push_str(checker.locator().slice(defaults[i - default_offset].range()));
+            }
+        }
+    }
+
+    let content = format!(
+        "class {name}({named_tuple_binding}):{line_ending}{body}",
+        name = target.id,
+    );
+    let fix_edit = Edit::range_replacement(content, assign.range());
+
+    Some(Fix::unsafe_edits(incomplete_edit, [named_tuple_edit, fix_edit]))
+}
+
+/// Collect the literal field names passed to `collections.namedtuple`, which may be
+/// given as a list/tuple of strings, or as a single whitespace/comma-separated string.
+/// Returns `None` if the expression isn't one of these literal forms.
+fn collect_namedtuple_field_names(expr: &Expr) -> Option<Vec<&str>> {
+    match expr {
+        Expr::List(ast::ExprList { elts, .. }) | Expr::Tuple(ast::ExprTuple { elts, .. }) => elts
+            .iter()
+            .map(|elt| match elt {
+                Expr::StringLiteral(s) => Some(s.value.to_str()),
+                _ => None,
+            })
+            .collect(),
+        Expr::StringLiteral(s) => Some(
+            s.value
+                .to_str()
+                .split(|c: char| c == ',' || c.is_whitespace())
+                .map(str::trim)
+                .filter(|part| !part.is_empty())
+                .collect(),
+        ),
+        _ => None,
+    }
+}
+
+/// Find the value of a keyword argument by name.
+fn find_keyword_value<'a>(call: &'a ExprCall, name: &str) -> Option<&'a Expr> {
+    call.arguments.keywords.iter().find_map(|keyword| {
+        keyword
+            .arg
+            .as_ref()
+            .is_some_and(|arg| arg.as_str() == name)
+            .then_some(&keyword.value)
+    })
+}
+
+/// Walk the field annotations and collect the names of any `TypeVar`/`ParamSpec`
+/// bindings they reference, in first-appearance order.
+///
+/// Returns `None` if an annotation references a name that can't be resolved to a
+/// binding in scope, in which case the whole fix is skipped. This is deliberately
+/// conservative, as requested: we can't tell such a name apart from a type
+/// variable that's merely out of scope (e.g. imported lazily, or guarded behind
+/// `TYPE_CHECKING`), and guessing wrong would silently drop a required `Generic`
+/// base from the generated class. Loosening this to treat unresolved names as
+/// "definitely not a type variable" is a separate, deliberate trade-off that
+/// needs its own sign-off rather than being folded in here.
+fn collect_type_vars<'a>(
+    checker: &Checker,
+    type_exprs: impl Iterator<Item = &'a Expr>,
+) -> Option<Vec<String>> {
+    let mut names = Vec::new();
+    for type_expr in type_exprs {
+        collect_names(type_expr, &mut names);
+    }
+
+    let mut type_vars = Vec::new();
+    for name in names {
+        let Some(binding_id) = checker.semantic().resolve_name(name) else {
+            return None;
+        };
+        let binding = checker.semantic().binding(binding_id);
+        if is_type_var_like_binding(checker, binding) && !type_vars.contains(&name.id.to_string())
+        {
+            type_vars.push(name.id.to_string());
+        }
+    }
+    Some(type_vars)
+}
+
+/// Returns `true` if `binding` was created by `typing.TypeVar(...)` or
+/// `typing.ParamSpec(...)` (including the `typing_extensions` equivalents).
+fn is_type_var_like_binding(checker: &Checker, binding: &ruff_python_semantic::Binding) -> bool {
+    let Some(Stmt::Assign(assign)) = binding.statement(checker.semantic()) else {
+        return false;
+    };
+    let Expr::Call(call) = assign.value.as_ref() else {
+        return false;
+    };
+    checker
+        .semantic()
+        .resolve_qualified_name(&call.func)
+        .is_some_and(|qualified_name| {
+            matches!(
+                qualified_name.segments(),
+                ["typing" | "typing_extensions", "TypeVar" | "ParamSpec"]
+            )
+        })
+}
+
+/// Recursively collect `Name` leaves that appear within a type annotation expression.
+fn collect_names<'a>(expr: &'a Expr, names: &mut Vec<&'a ast::ExprName>) {
+    match expr {
+        Expr::Name(name) => names.push(name),
+        Expr::Subscript(ast::ExprSubscript { value, slice, .. }) => {
+            collect_names(value, names);
+            collect_names(slice, names);
+        }
+        Expr::BinOp(ast::ExprBinOp { left, right, .. }) => {
+            collect_names(left, names);
+            collect_names(right, names);
+        }
+        Expr::Tuple(ast::ExprTuple { elts, .. }) | Expr::List(ast::ExprList { elts, .. }) => {
+            for elt in elts {
+                collect_names(elt, names);
+            }
+        }
+        Expr::Attribute(ast::ExprAttribute { value, .. }) => collect_names(value, names),
+        _ => {}
+    }
+}
+
+/// Collect `(name, type)` members from the list-of-pairs form of the functional
+/// constructor, e.g. `[("name", str), ("age", int)]`.
+fn collect_list_members(fields: &Expr) -> Option<Vec<(&str, &Expr)>> {
+    let elts = match fields {
+        Expr::List(ast::ExprList { elts, .. }) => elts.as_slice(),
+        Expr::Tuple(ast::ExprTuple { elts, .. }) => elts.as_slice(),
+        _ => return None,
+    };
+
+    let mut members = Vec::with_capacity(elts.len());
+    for elt in elts {
+        let elts = match elt {
+            Expr::Tuple(ast::ExprTuple { elts, .. }) => elts.as_slice(),
+            Expr::List(ast::ExprList { elts, .. }) => elts.as_slice(),
+            _ => return None,
+        };
+        let [Expr::StringLiteral(name), type_expr] = elts else {
+            return None;
+        };
+        members.push((name.value.to_str(), type_expr));
+    }
+    Some(members)
+}
+
+/// Collect `(name, type)` members from the keyword form of the functional
+/// constructor, e.g. `NamedTuple("Person", name=str, age=int)`.
+fn collect_keyword_members(keywords: &[Keyword]) -> Option<Vec<(&str, &Expr)>> {
+    let mut members = Vec::with_capacity(keywords.len());
+    for keyword in keywords {
+        let name = keyword.arg.as_ref()?.as_str();
+        // A keyword matching a Python keyword, or that isn't a valid identifier, can't
+        // round-trip through an annotated assignment; keep the diagnostic but skip the fix.
+        if is_keyword(name) || !is_identifier(name) {
+            return None;
+        }
+        members.push((name, &keyword.value));
+    }
+    Some(members)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use anyhow::Result;
+
+    use crate::registry::Rule;
+    use crate::test::test_path;
+    use crate::{assert_messages, settings};
+
+    #[test]
+    fn pyi028() -> Result<()> {
+        let diagnostics = test_path(
+            Path::new("flake8_pyi/PYI028.pyi"),
+            &settings::LinterSettings::for_rule(Rule::NamedTupleAssignment),
+        )?;
+        assert_messages!(diagnostics);
+        Ok(())
     }
 }
@@ -0,0 +1,129 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::{self as ast, Stmt};
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// Members that clash with the tuple machinery `typing.NamedTuple` generates for
+/// every subclass. Declaring a field or method with one of these names either
+/// breaks at runtime or silently shadows the generated behavior.
+const PROHIBITED_MEMBERS: &[&str] = &[
+    "__new__",
+    "__init__",
+    "__slots__",
+    "__getnewargs__",
+    "_fields",
+    "_field_defaults",
+    "_field_types",
+    "_make",
+    "_replace",
+    "_asdict",
+    "_source",
+    "__annotations__",
+];
+
+/// ## What it does
+/// Checks for `typing.NamedTuple` or `typing_extensions.NamedTuple` class
+/// definitions that declare a field or method with a name that collides
+/// with one of the attributes generated by the `NamedTuple` machinery.
+///
+/// ## Why is this bad?
+/// `NamedTuple` generates several special attributes and methods itself
+/// (`_fields`, `_make`, `_replace`, `_asdict`, `__new__`, `__slots__`, ...).
+/// Redeclaring one of these in the class body either raises a `TypeError`
+/// at class creation time, or silently overrides generated behavior that
+/// other code relies on.
+///
+/// ## Example
+/// ```python
+/// from typing import NamedTuple
+///
+///
+/// class Foo(NamedTuple):
+///     _fields: int
+/// ```
+///
+/// Use instead:
+/// ```python
+/// from typing import NamedTuple
+///
+///
+/// class Foo(NamedTuple):
+///     fields: int
+/// ```
+#[violation]
+pub struct NamedTupleProhibitedField {
+    member: String,
+}
+
+impl Violation for NamedTupleProhibitedField {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let NamedTupleProhibitedField { member } = self;
+        format!("`{member}` conflicts with the `NamedTuple` generated implementation and must be removed or renamed")
+    }
+}
+
+/// PYI066
+pub(crate) fn named_tuple_prohibited_fields(checker: &mut Checker, class_def: &ast::StmtClassDef) {
+    let is_named_tuple = class_def.bases().iter().any(|base| {
+        checker
+            .semantic()
+            .resolve_qualified_name(base)
+            .is_some_and(|qualified_name| {
+                matches!(
+                    qualified_name.segments(),
+                    ["typing" | "typing_extensions", "NamedTuple"] | ["NamedTuple"]
+                )
+            })
+    });
+    if !is_named_tuple {
+        return;
+    }
+
+    for stmt in &class_def.body {
+        let (name, range) = match stmt {
+            Stmt::AnnAssign(ast::StmtAnnAssign { target, .. }) => {
+                let ast::Expr::Name(name) = target.as_ref() else {
+                    continue;
+                };
+                (name.id.as_str(), name.range())
+            }
+            Stmt::FunctionDef(ast::StmtFunctionDef { name, .. }) => {
+                (name.as_str(), name.range())
+            }
+            _ => continue,
+        };
+
+        if PROHIBITED_MEMBERS.contains(&name) {
+            checker.diagnostics.push(Diagnostic::new(
+                NamedTupleProhibitedField {
+                    member: name.to_string(),
+                },
+                range,
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use anyhow::Result;
+
+    use crate::registry::Rule;
+    use crate::test::test_path;
+    use crate::{assert_messages, settings};
+
+    #[test]
+    fn pyi066() -> Result<()> {
+        let diagnostics = test_path(
+            Path::new("flake8_pyi/PYI066.pyi"),
+            &settings::LinterSettings::for_rule(Rule::NamedTupleProhibitedField),
+        )?;
+        assert_messages!(diagnostics);
+        Ok(())
+    }
+}